@@ -0,0 +1,206 @@
+// src/config.rs
+
+use eyre::{eyre, Result};
+use serde::Deserialize;
+use shellexpand::tilde;
+use std::env;
+use std::fs;
+
+/// A named AI provider endpoint, e.g. OpenAI itself, an Azure deployment, or a local proxy.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ClientConfig {
+    pub name: String,
+    pub api_base: String,
+    pub model: String,
+    /// The API key itself. Takes precedence over `api_key_env` when both are set; prefer the
+    /// latter so secrets don't have to live in the config file.
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Name of an environment variable to read the API key from.
+    #[serde(default)]
+    pub api_key_env: Option<String>,
+    #[serde(default)]
+    pub organization_id: Option<String>,
+    /// HTTP(S) proxy URL to route requests to this client through, e.g. behind a corporate or
+    /// privacy proxy. Falls back to `HTTPS_PROXY` when neither this nor `--proxy` is set.
+    #[serde(default)]
+    pub proxy: Option<String>,
+}
+
+impl ClientConfig {
+    pub fn resolve_api_key(&self) -> Result<String> {
+        if let Some(key) = &self.api_key {
+            return Ok(key.clone());
+        }
+        if let Some(var) = &self.api_key_env {
+            return env::var(var).map_err(|_| eyre!("Environment variable '{}' not set for client '{}'", var, self.name));
+        }
+        Err(eyre!("Client '{}' has neither 'api_key' nor 'api_key_env' set", self.name))
+    }
+}
+
+/// A named prompt style with its own template and optional generation overrides, borrowed from
+/// aichat's role concept so a user can keep several reword styles (formal, concise, friendly, ...).
+#[derive(Debug, Deserialize, Clone)]
+pub struct RoleConfig {
+    pub name: String,
+    pub prompt: String,
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub system: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct Config {
+    #[serde(default, rename = "clients")]
+    pub clients: Vec<ClientConfig>,
+    #[serde(default, rename = "roles")]
+    pub roles: Vec<RoleConfig>,
+}
+
+impl Config {
+    pub fn load(path: &str) -> Result<Config> {
+        let expanded_path = tilde(path);
+        match fs::read_to_string(expanded_path.as_ref()) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| eyre!("Failed to parse config file '{}': {}", expanded_path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(eyre!("Failed to read config file '{}': {}", expanded_path, e)),
+        }
+    }
+
+    pub fn client(&self, name: Option<&str>) -> Result<ClientConfig> {
+        match name {
+            Some(name) => self
+                .clients
+                .iter()
+                .find(|c| c.name == name)
+                .cloned()
+                .ok_or_else(|| eyre!("No client named '{}' in config", name)),
+            None => self.clients.first().cloned().ok_or_else(|| {
+                eyre!("No clients configured; add a [[clients]] table to the config or pass --client")
+            }),
+        }
+    }
+
+    pub fn role(&self, name: &str) -> Result<RoleConfig> {
+        self.roles
+            .iter()
+            .find(|r| r.name == name)
+            .cloned()
+            .ok_or_else(|| eyre!("No role named '{}' in config", name))
+    }
+}
+
+/// The built-in default when the config file is missing or declares no clients (and `--client`
+/// is not given), so `nerf` keeps
+/// working out of the box against OpenAI with just `CHATGPT_API_KEY` set.
+pub fn default_client() -> ClientConfig {
+    ClientConfig {
+        name: "openai".to_string(),
+        api_base: "https://api.openai.com".to_string(),
+        model: "gpt-3.5-turbo-16k".to_string(),
+        api_key: None,
+        api_key_env: Some("CHATGPT_API_KEY".to_string()),
+        organization_id: None,
+        proxy: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r#"
+        [[clients]]
+        name = "openai"
+        api_base = "https://api.openai.com"
+        model = "gpt-4"
+        api_key_env = "CHATGPT_API_KEY"
+
+        [[clients]]
+        name = "azure"
+        api_base = "https://example.openai.azure.com"
+        model = "gpt-4"
+        api_key = "inline-key"
+        proxy = "http://azure-proxy"
+
+        [[roles]]
+        name = "formal"
+        prompt = "Rewrite formally: {input}"
+        temperature = 0.2
+    "#;
+
+    fn write_config(contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("nerf-test-config-{:?}.toml", std::thread::current().id()));
+        fs::write(&path, contents).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn parses_clients_and_roles_from_toml() {
+        let config: Config = toml::from_str(TOML).unwrap();
+        assert_eq!(config.clients.len(), 2);
+        assert_eq!(config.roles.len(), 1);
+        assert_eq!(config.clients[1].name, "azure");
+        assert_eq!(config.roles[0].temperature, Some(0.2));
+    }
+
+    #[test]
+    fn client_defaults_to_first_when_unnamed() {
+        let config: Config = toml::from_str(TOML).unwrap();
+        assert_eq!(config.client(None).unwrap().name, "openai");
+    }
+
+    #[test]
+    fn client_errors_on_unknown_name() {
+        let config: Config = toml::from_str(TOML).unwrap();
+        assert!(config.client(Some("nonexistent")).is_err());
+    }
+
+    #[test]
+    fn role_errors_on_unknown_name() {
+        let config: Config = toml::from_str(TOML).unwrap();
+        assert!(config.role("nonexistent").is_err());
+    }
+
+    #[test]
+    fn load_returns_default_when_file_missing() {
+        let config = Config::load("~/nerf-test-config-does-not-exist.toml").unwrap();
+        assert!(config.clients.is_empty());
+        assert!(config.roles.is_empty());
+    }
+
+    #[test]
+    fn load_parses_existing_file() {
+        let path = write_config(TOML);
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.clients.len(), 2);
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn resolve_api_key_prefers_inline_key_over_env() {
+        let config: Config = toml::from_str(TOML).unwrap();
+        let mut azure = config.client(Some("azure")).unwrap();
+        azure.api_key_env = Some("CHATGPT_API_KEY".to_string());
+        assert_eq!(azure.resolve_api_key().unwrap(), "inline-key");
+    }
+
+    #[test]
+    fn resolve_api_key_errors_when_neither_set() {
+        let client = ClientConfig {
+            name: "broken".to_string(),
+            api_base: "https://example.com".to_string(),
+            model: "gpt-4".to_string(),
+            api_key: None,
+            api_key_env: None,
+            organization_id: None,
+            proxy: None,
+        };
+        assert!(client.resolve_api_key().is_err());
+    }
+}
@@ -1,11 +1,17 @@
 // src/main.rs
 
+mod config;
+mod repl;
+
+use arboard::Clipboard;
 use clap::Parser;
+use config::{default_client, ClientConfig, Config, RoleConfig};
 use env_logger::{Builder, Env};
 use eyre::{eyre, Result};
+use futures_util::StreamExt;
 use lazy_static::lazy_static;
 use log::{debug, info};
-use reqwest::blocking::Client;
+use reqwest::Client;
 use serde_json::json;
 use shellexpand::tilde;
 use std::env;
@@ -14,18 +20,98 @@ use std::io::Write;
 use std::process::{Command, Stdio};
 
 lazy_static! {
-    static ref CHATGPT_API_KEY: String = env::var("CHATGPT_API_KEY").expect("CHATGPT_API_KEY not set in environment");
     static ref RUST_LOG: String = env::var("RUST_LOG").unwrap_or_else(|_| "WARNING".to_string());
 }
 
 #[derive(Parser, Debug)]
 #[command(name = "nerf", version = env!("GIT_DESCRIBE"), about = "AI-powered text processing tool")]
 struct Cli {
-    #[arg(required = true)]
+    #[arg(required_unless_present = "repl")]
     words: Vec<String>,
 
     #[arg(short, long, default_value = "~/.config/nerf/prompt")]
     prompt: String,
+
+    /// Stream the reply token-by-token via SSE instead of blocking until completion
+    #[arg(long)]
+    stream: bool,
+
+    /// Path to the nerf config file declaring available clients
+    #[arg(long, default_value = "~/.config/nerf/config.toml")]
+    config: String,
+
+    /// Name of the configured client to use (defaults to the first one in the config)
+    #[arg(long)]
+    client: Option<String>,
+
+    /// Name of a configured role, overriding --prompt with the role's template and generation settings
+    #[arg(long)]
+    role: Option<String>,
+
+    /// Skip copying the reworded text to the clipboard entirely
+    #[arg(long)]
+    no_clipboard: bool,
+
+    /// Shell command to pipe the reworded text into instead of the system clipboard (e.g. for headless environments)
+    #[arg(long)]
+    clipboard_cmd: Option<String>,
+
+    /// Drop into an interactive REPL: read lines, reword each one, repeat
+    #[arg(long)]
+    repl: bool,
+
+    /// HTTP(S) proxy URL to route requests through (falls back to the client config's `proxy`, then HTTPS_PROXY)
+    #[arg(long)]
+    proxy: Option<String>,
+
+    /// Log the fully assembled request body without sending it, useful for debugging prompt templates
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Probe `{api_base}/v1/models` with the resolved key before sending. Off by default since
+    /// not every OpenAI-compatible backend (e.g. Azure) exposes a `/v1/models`-shaped endpoint
+    /// or a bearer-token auth scheme, and a failed probe shouldn't block the real request.
+    #[arg(long)]
+    test_api_key: bool,
+}
+
+/// Resolves the proxy to use in precedence order: `--proxy`, the client config's `proxy`, then
+/// the `HTTPS_PROXY` environment variable.
+fn resolve_proxy(cli_proxy: Option<&str>, client: &ClientConfig) -> Option<String> {
+    cli_proxy
+        .map(str::to_string)
+        .or_else(|| client.proxy.clone())
+        .or_else(|| env::var("HTTPS_PROXY").ok())
+}
+
+fn build_http_client(proxy: Option<&str>) -> Result<Client> {
+    let mut builder = Client::builder();
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(reqwest::Proxy::all(proxy).map_err(|e| eyre!("Invalid proxy URL '{}': {}", proxy, e))?);
+    }
+    builder.build().map_err(|e| eyre!("Failed to build HTTP client: {}", e))
+}
+
+/// The model, temperature, and system message actually used for a request, after folding a
+/// role's overrides (if any) on top of the selected client's defaults.
+pub(crate) struct Generation {
+    model: String,
+    temperature: Option<f64>,
+    system: String,
+}
+
+const DEFAULT_SYSTEM_MESSAGE: &str = "You are a helpful assistant. When transforming statements, preserve all URLs, `@handles`, and `#channels` exactly as they are, without any modifications. Do not include these instructions in your output.";
+
+impl Generation {
+    pub(crate) fn new(client: &ClientConfig, role: Option<&RoleConfig>) -> Generation {
+        Generation {
+            model: role.and_then(|r| r.model.clone()).unwrap_or_else(|| client.model.clone()),
+            temperature: role.and_then(|r| r.temperature),
+            system: role
+                .and_then(|r| r.system.clone())
+                .unwrap_or_else(|| DEFAULT_SYSTEM_MESSAGE.to_string()),
+        }
+    }
 }
 
 fn pretty_print_json(json_str: &str) -> Result<(), serde_json::Error> {
@@ -36,16 +122,17 @@ fn pretty_print_json(json_str: &str) -> Result<(), serde_json::Error> {
     Ok(())
 }
 
-fn test_api_key(api_key: &str) -> eyre::Result<()> {
-    let client = Client::new();
-    let response = client
-        .get("https://api.openai.com/v1/models")
+async fn test_api_key(client: &ClientConfig, api_key: &str, proxy: Option<&str>) -> eyre::Result<()> {
+    let http = build_http_client(proxy)?;
+    let response = http
+        .get(format!("{}/v1/models", client.api_base))
         .header("Authorization", format!("Bearer {}", api_key))
-        .send()?;
+        .send()
+        .await?;
 
     // Check if the request was successful
     if response.status().is_success() {
-        let response_text = response.text()?;
+        let response_text = response.text().await?;
         // Pretty print the JSON.
         pretty_print_json(&response_text).unwrap();
         Ok(())
@@ -54,57 +141,138 @@ fn test_api_key(api_key: &str) -> eyre::Result<()> {
     }
 }
 
-fn main() -> Result<()> {
+#[tokio::main]
+async fn main() -> Result<()> {
     init_logger();
 
-    test_api_key(&CHATGPT_API_KEY)?;
-
     let cli = Cli::parse();
+
+    let config = Config::load(&cli.config)?;
+    let client = match config.client(cli.client.as_deref()) {
+        Ok(client) => client,
+        Err(_) if cli.client.is_none() => default_client(),
+        Err(e) => return Err(e),
+    };
+    let proxy = resolve_proxy(cli.proxy.as_deref(), &client);
+    debug!("Using client '{}' ({})", client.name, client.api_base);
+
+    if cli.repl && cli.dry_run {
+        return Err(eyre!("--dry-run is not supported together with --repl"));
+    }
+
+    if cli.repl {
+        let api_key = client.resolve_api_key()?;
+        if cli.test_api_key {
+            test_api_key(&client, &api_key, proxy.as_deref()).await?;
+        }
+
+        return repl::run(repl::ReplOptions {
+            config,
+            client,
+            api_key,
+            proxy: cli.proxy,
+            role: cli.role,
+            prompt_path: cli.prompt,
+            stream: cli.stream,
+            no_clipboard: cli.no_clipboard,
+            clipboard_cmd: cli.clipboard_cmd,
+        })
+        .await;
+    }
+
     let input = cli.words.join(" ");
     info!("Input sentence(s): {}", input);
 
-    let prompt_template = load_prompt(&cli.prompt)?;
+    let role = cli.role.as_deref().map(|name| config.role(name)).transpose()?;
+
+    let prompt_template = match &role {
+        Some(role) => role.prompt.clone(),
+        None => load_prompt(&cli.prompt)?,
+    };
     debug!("Loaded prompt template: {}", prompt_template);
 
     let prompt = prompt_template.replace("{input}", &input);
     debug!("Final prompt to send: {}", prompt);
 
+    let generation = Generation::new(&client, role.as_ref());
+
+    if cli.dry_run {
+        let request_body = chat_request_body(&generation, &prompt, cli.stream);
+        pretty_print_json(&request_body.to_string())?;
+        return Ok(());
+    }
+
+    let api_key = client.resolve_api_key()?;
+    if cli.test_api_key {
+        test_api_key(&client, &api_key, proxy.as_deref()).await?;
+    }
+
     println!("{}", "*".repeat(80));
 
-    let reworded = send_to_chatgpt(&prompt)?;
-    println!("{}", reworded);
+    let reworded = if cli.stream {
+        send_to_chatgpt_streaming(&client, &api_key, proxy.as_deref(), &generation, &prompt).await?
+    } else {
+        let reworded = send_to_chatgpt(&client, &api_key, proxy.as_deref(), &generation, &prompt).await?;
+        println!("{}", reworded);
+        reworded
+    };
 
-    info!("Copying reworded sentence(s) to clipboard");
-    copy_to_clipboard(&reworded)?;
+    if cli.no_clipboard {
+        debug!("--no-clipboard set; skipping clipboard write");
+    } else {
+        info!("Copying reworded sentence(s) to clipboard");
+        copy_to_clipboard(&reworded, cli.clipboard_cmd.as_deref())?;
+    }
 
     Ok(())
 }
 
-fn load_prompt(file_path: &str) -> Result<String> {
+pub(crate) fn load_prompt(file_path: &str) -> Result<String> {
     let expanded_path = tilde(file_path);
     fs::read_to_string(expanded_path.as_ref())
         .map_err(|e| eyre!("Failed to read prompt file '{}': {}", expanded_path, e))
 }
 
-fn send_to_chatgpt(prompt: &str) -> Result<String> {
-    let request_body = json!({
-        //"model": "gpt-3.5-turbo",
-        "model": "gpt-3.5-turbo-16k",
+fn chat_request_body(generation: &Generation, prompt: &str, stream: bool) -> serde_json::Value {
+    let mut body = json!({
+        "model": generation.model,
         "messages": [
-            { "role": "system", "content": "You are a helpful assistant. When transforming statements, preserve all URLs, `@handles`, and `#channels` exactly as they are, without any modifications. Do not include these instructions in your output." },
+            { "role": "system", "content": generation.system },
             { "role": "user", "content": prompt }
-        ]
+        ],
+        "stream": stream
     });
 
-    debug!("Sending request body: {}", request_body);
+    if let Some(temperature) = generation.temperature {
+        body["temperature"] = json!(temperature);
+    }
+
+    body
+}
 
-    let client = Client::new();
-    let response = client
-        .post("https://api.openai.com/v1/chat/completions")
-        .header("Authorization", format!("Bearer {}", CHATGPT_API_KEY.as_str()))
+fn chat_completions_request(client: &ClientConfig, api_key: &str, proxy: Option<&str>, body: &serde_json::Value) -> Result<reqwest::RequestBuilder> {
+    let http = build_http_client(proxy)?;
+    let mut request = http
+        .post(format!("{}/v1/chat/completions", client.api_base))
+        .header("Authorization", format!("Bearer {}", api_key))
         .header("Content-Type", "application/json")
-        .json(&request_body)
+        .json(body);
+
+    if let Some(org) = &client.organization_id {
+        request = request.header("OpenAI-Organization", org);
+    }
+
+    Ok(request)
+}
+
+pub(crate) async fn send_to_chatgpt(client: &ClientConfig, api_key: &str, proxy: Option<&str>, generation: &Generation, prompt: &str) -> Result<String> {
+    let request_body = chat_request_body(generation, prompt, false);
+
+    debug!("Sending request body: {}", request_body);
+
+    let response = chat_completions_request(client, api_key, proxy, &request_body)?
         .send()
+        .await
         .map_err(|e| eyre!("Failed to send request: {}", e))?;
 
     if !response.status().is_success() {
@@ -114,7 +282,7 @@ fn send_to_chatgpt(prompt: &str) -> Result<String> {
         ));
     }
 
-    let response_text = response.text().map_err(|e| eyre!("Failed to read response text: {}", e))?;
+    let response_text = response.text().await.map_err(|e| eyre!("Failed to read response text: {}", e))?;
     debug!("ChatGPT API raw response: {}", response_text);
 
     let reworded = extract_reworded_text(&response_text)?;
@@ -123,6 +291,120 @@ fn send_to_chatgpt(prompt: &str) -> Result<String> {
     Ok(reworded)
 }
 
+/// Sends the prompt with `stream: true` and incrementally prints each delta as it arrives,
+/// returning the fully accumulated reply once the stream ends.
+pub(crate) async fn send_to_chatgpt_streaming(client: &ClientConfig, api_key: &str, proxy: Option<&str>, generation: &Generation, prompt: &str) -> Result<String> {
+    let request_body = chat_request_body(generation, prompt, true);
+
+    debug!("Sending streaming request body: {}", request_body);
+
+    let response = chat_completions_request(client, api_key, proxy, &request_body)?
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to send request: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(eyre!(
+            "ChatGPT API call failed with status: {}",
+            response.status()
+        ));
+    }
+
+    let mut accumulated = String::new();
+    // Raw bytes, not a String: TCP/SSE chunk boundaries are arbitrary and can split a multibyte
+    // UTF-8 sequence in two, so we only decode once a full event has been buffered.
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut stream = response.bytes_stream();
+
+    'outer: while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| eyre!("Failed to read SSE chunk: {}", e))?;
+        buffer.extend_from_slice(&chunk);
+
+        for event in drain_sse_events(&mut buffer)? {
+            let parsed = parse_sse_event(&event)?;
+            for delta in parsed.deltas {
+                print!("{}", delta);
+                std::io::stdout().flush().ok();
+                accumulated.push_str(&delta);
+            }
+            if parsed.done {
+                break 'outer;
+            }
+        }
+    }
+
+    println!();
+    info!("Reworded sentence(s): {}", accumulated);
+
+    Ok(accumulated)
+}
+
+/// Finds the first occurrence of `needle` in `haystack`, operating on raw bytes so a partial
+/// multibyte UTF-8 sequence at a chunk boundary never has to be decoded before a delimiter arrives.
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+/// Drains complete (blank-line-terminated) SSE events out of `buffer`, decoding each as UTF-8
+/// only once it's whole. Any trailing partial event — including a partial multibyte UTF-8
+/// sequence — is left buffered for the next chunk rather than decoded early.
+fn drain_sse_events(buffer: &mut Vec<u8>) -> Result<Vec<String>> {
+    let mut events = Vec::new();
+
+    while let Some(event_end) = find_subslice(buffer, b"\n\n") {
+        let event_bytes: Vec<u8> = buffer.drain(..event_end + 2).collect();
+        let event = String::from_utf8(event_bytes[..event_end].to_vec())
+            .map_err(|e| eyre!("SSE event was not valid UTF-8: {}", e))?;
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+/// The effect of one SSE event: any content deltas it carried (a frame can hold multiple `data:`
+/// lines), and whether it was the terminal `[DONE]` sentinel. A stream that ends without ever
+/// sending `[DONE]` is handled by the caller simply running out of chunks, not by this function.
+struct SseEvent {
+    deltas: Vec<String>,
+    done: bool,
+}
+
+fn parse_sse_event(event: &str) -> Result<SseEvent> {
+    let mut deltas = Vec::new();
+    let mut done = false;
+
+    for line in event.lines() {
+        let Some(data) = line.strip_prefix("data: ") else {
+            continue;
+        };
+
+        if data == "[DONE]" {
+            done = true;
+            break;
+        }
+
+        let delta = extract_delta_content(data)?;
+        if !delta.is_empty() {
+            deltas.push(delta);
+        }
+    }
+
+    Ok(SseEvent { deltas, done })
+}
+
+/// Extracts `choices[0].delta.content` from a single SSE `data:` payload, tolerating chunks
+/// where the delta is absent or empty (e.g. the role-only opening chunk).
+fn extract_delta_content(data: &str) -> Result<String> {
+    let chunk_json: serde_json::Value = serde_json::from_str(data)
+        .map_err(|e| eyre!("Failed to parse SSE chunk as JSON: {}", e))?;
+
+    Ok(chunk_json["choices"]
+        .get(0)
+        .and_then(|choice| choice["delta"]["content"].as_str())
+        .unwrap_or("")
+        .to_string())
+}
+
 fn extract_reworded_text(response: &str) -> Result<String> {
     let response_json: serde_json::Value = serde_json::from_str(response)
         .map_err(|e| eyre!("Failed to parse API response as JSON: {}", e))?;
@@ -134,23 +416,57 @@ fn extract_reworded_text(response: &str) -> Result<String> {
         .ok_or_else(|| eyre!("Failed to extract reworded text from response"))
 }
 
-fn copy_to_clipboard(text: &str) -> Result<()> {
-    let mut process = Command::new("xclip")
-        .arg("-selection")
-        .arg("clipboard")
+/// Copies `text` to the system clipboard via `arboard`, or pipes it into `clipboard_cmd` when
+/// given, which is useful in headless environments without a clipboard provider.
+pub(crate) fn copy_to_clipboard(text: &str, clipboard_cmd: Option<&str>) -> Result<()> {
+    if let Some(cmd) = clipboard_cmd {
+        return copy_via_command(text, cmd);
+    }
+
+    set_clipboard_text(text)
+}
+
+/// On X11/Wayland, arboard only serves the clipboard selection for as long as its connection
+/// lives, so a bare `set_text` would go empty the instant this process exits. `SetExtLinux::wait()`
+/// forks a short-lived process to hold the selection until another program actually pastes it,
+/// matching what `xclip` did for us for free.
+#[cfg(target_os = "linux")]
+fn set_clipboard_text(text: &str) -> Result<()> {
+    use arboard::SetExtLinux;
+
+    let mut clipboard = Clipboard::new().map_err(|e| eyre!("Failed to access system clipboard: {}", e))?;
+    clipboard
+        .set()
+        .wait()
+        .text(text)
+        .map_err(|e| eyre!("Failed to copy text to clipboard: {}", e))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_clipboard_text(text: &str) -> Result<()> {
+    let mut clipboard = Clipboard::new().map_err(|e| eyre!("Failed to access system clipboard: {}", e))?;
+    clipboard
+        .set_text(text)
+        .map_err(|e| eyre!("Failed to copy text to clipboard: {}", e))
+}
+
+fn copy_via_command(text: &str, cmd: &str) -> Result<()> {
+    let mut process = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
         .stdin(Stdio::piped())
         .spawn()
-        .map_err(|_| eyre!("Failed to start xclip. Is it installed?"))?;
+        .map_err(|e| eyre!("Failed to start clipboard command '{}': {}", cmd, e))?;
 
     if let Some(stdin) = process.stdin.as_mut() {
         stdin.write_all(text.as_bytes())?;
     } else {
-        return Err(eyre!("Failed to access stdin for xclip"));
+        return Err(eyre!("Failed to access stdin for clipboard command '{}'", cmd));
     }
 
     let status = process.wait()?;
     if !status.success() {
-        return Err(eyre!("xclip process failed with status: {}", status));
+        return Err(eyre!("Clipboard command '{}' failed with status: {}", cmd, status));
     }
 
     Ok(())
@@ -159,3 +475,130 @@ fn copy_to_clipboard(text: &str) -> Result<()> {
 fn init_logger() {
     Builder::from_env(Env::default().default_filter_or(RUST_LOG.as_str())).init();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(name: &str, proxy: Option<&str>) -> ClientConfig {
+        ClientConfig {
+            name: name.to_string(),
+            api_base: "https://api.openai.com".to_string(),
+            model: "gpt-4".to_string(),
+            api_key: Some("sk-test".to_string()),
+            api_key_env: None,
+            organization_id: None,
+            proxy: proxy.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn find_subslice_locates_delimiter() {
+        assert_eq!(find_subslice(b"data: a\n\ndata: b\n\n", b"\n\n"), Some(7));
+        assert_eq!(find_subslice(b"no delimiter here", b"\n\n"), None);
+    }
+
+    #[test]
+    fn drain_sse_events_splits_multiple_complete_events() {
+        let mut buffer = b"data: one\n\ndata: two\n\n".to_vec();
+        let events = drain_sse_events(&mut buffer).unwrap();
+        assert_eq!(events, vec!["data: one", "data: two"]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn drain_sse_events_leaves_partial_event_buffered() {
+        let mut buffer = b"data: one\n\ndata: tw".to_vec();
+        let events = drain_sse_events(&mut buffer).unwrap();
+        assert_eq!(events, vec!["data: one"]);
+        assert_eq!(buffer, b"data: tw");
+    }
+
+    #[test]
+    fn drain_sse_events_reassembles_split_multibyte_utf8() {
+        // "café" ends in 'é' (0xC3 0xA9); split the chunk between those two bytes.
+        let content = "caf\u{e9}";
+        let mut full_event = format!("data: {}\n\n", content).into_bytes();
+        let split_at = full_event.len() - 3;
+        let second_half = full_event.split_off(split_at);
+
+        let mut buffer = full_event;
+        assert!(drain_sse_events(&mut buffer).unwrap().is_empty());
+        assert!(!buffer.is_empty());
+
+        buffer.extend_from_slice(&second_half);
+        let events = drain_sse_events(&mut buffer).unwrap();
+        assert_eq!(events, vec![format!("data: {}", content)]);
+    }
+
+    #[test]
+    fn parse_sse_event_handles_multiple_data_lines_in_one_frame() {
+        let event = "data: {\"choices\":[{\"delta\":{\"content\":\"foo\"}}]}\ndata: {\"choices\":[{\"delta\":{\"content\":\"bar\"}}]}";
+        let parsed = parse_sse_event(event).unwrap();
+        assert_eq!(parsed.deltas, vec!["foo", "bar"]);
+        assert!(!parsed.done);
+    }
+
+    #[test]
+    fn parse_sse_event_ignores_absent_or_empty_delta() {
+        let event = "data: {\"choices\":[{\"delta\":{}}]}";
+        let parsed = parse_sse_event(event).unwrap();
+        assert!(parsed.deltas.is_empty());
+        assert!(!parsed.done);
+    }
+
+    #[test]
+    fn parse_sse_event_recognizes_done_sentinel() {
+        let event = "data: [DONE]";
+        let parsed = parse_sse_event(event).unwrap();
+        assert!(parsed.deltas.is_empty());
+        assert!(parsed.done);
+    }
+
+    #[test]
+    fn stream_ending_without_done_still_yields_accumulated_text() {
+        // A stream that never sends `[DONE]` simply runs out of chunks; the caller's loop ends
+        // without ever seeing `done == true`, and whatever deltas were parsed are still returned.
+        let mut buffer = b"data: {\"choices\":[{\"delta\":{\"content\":\"partial\"}}]}\n\n".to_vec();
+        let events = drain_sse_events(&mut buffer).unwrap();
+        let parsed: Vec<SseEvent> = events.iter().map(|e| parse_sse_event(e).unwrap()).collect();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].deltas, vec!["partial"]);
+        assert!(!parsed[0].done);
+    }
+
+    #[test]
+    fn resolve_proxy_prefers_cli_then_client_then_env() {
+        let with_client_proxy = client("c", Some("http://client-proxy"));
+        assert_eq!(
+            resolve_proxy(Some("http://cli-proxy"), &with_client_proxy),
+            Some("http://cli-proxy".to_string())
+        );
+        assert_eq!(resolve_proxy(None, &with_client_proxy), Some("http://client-proxy".to_string()));
+
+        let without_client_proxy = client("c", None);
+        assert_eq!(resolve_proxy(None, &without_client_proxy), env::var("HTTPS_PROXY").ok());
+    }
+
+    #[test]
+    fn generation_folds_role_over_client_defaults() {
+        let c = client("c", None);
+
+        let generation = Generation::new(&c, None);
+        assert_eq!(generation.model, "gpt-4");
+        assert_eq!(generation.temperature, None);
+        assert_eq!(generation.system, DEFAULT_SYSTEM_MESSAGE);
+
+        let role = RoleConfig {
+            name: "formal".to_string(),
+            prompt: "{input}".to_string(),
+            temperature: Some(0.2),
+            model: Some("gpt-4-turbo".to_string()),
+            system: Some("Be formal.".to_string()),
+        };
+        let generation = Generation::new(&c, Some(&role));
+        assert_eq!(generation.model, "gpt-4-turbo");
+        assert_eq!(generation.temperature, Some(0.2));
+        assert_eq!(generation.system, "Be formal.");
+    }
+}
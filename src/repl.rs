@@ -0,0 +1,157 @@
+// src/repl.rs
+
+use crate::config::{ClientConfig, Config};
+use crate::{copy_to_clipboard, load_prompt, resolve_proxy, send_to_chatgpt, send_to_chatgpt_streaming, Generation};
+use eyre::Result;
+use log::{debug, info};
+use reedline::{DefaultPrompt, DefaultPromptSegment, Reedline, Signal};
+
+pub(crate) struct ReplOptions {
+    pub(crate) config: Config,
+    pub(crate) client: ClientConfig,
+    pub(crate) api_key: String,
+    /// `--proxy` as given; resolved against the active client for each request.
+    pub(crate) proxy: Option<String>,
+    pub(crate) role: Option<String>,
+    pub(crate) prompt_path: String,
+    pub(crate) stream: bool,
+    pub(crate) no_clipboard: bool,
+    pub(crate) clipboard_cmd: Option<String>,
+}
+
+/// State that can change mid-session via `:role` and `:client` commands, kept separate from the
+/// one-time setup in `ReplOptions` so switching doesn't require re-parsing CLI args.
+struct Session {
+    client: ClientConfig,
+    api_key: String,
+    role_name: Option<String>,
+    prompt_template: String,
+    generation: Generation,
+}
+
+impl Session {
+    fn load(config: &Config, client: ClientConfig, api_key: String, role_name: Option<String>, prompt_path: &str) -> Result<Session> {
+        let role = role_name.as_deref().map(|name| config.role(name)).transpose()?;
+        let prompt_template = match &role {
+            Some(role) => role.prompt.clone(),
+            None => load_prompt(prompt_path)?,
+        };
+        let generation = Generation::new(&client, role.as_ref());
+        Ok(Session {
+            client,
+            api_key,
+            role_name,
+            prompt_template,
+            generation,
+        })
+    }
+}
+
+/// Runs an interactive loop: each line is reworded through the current prompt template and
+/// printed, without re-reading the prompt file or re-validating the API key per line. Typing
+/// `:role <name>` or `:client <name>` switches the active role/client for subsequent lines, and
+/// `:quit` (or `:exit`) ends the session.
+pub(crate) async fn run(opts: ReplOptions) -> Result<()> {
+    let mut session = Session::load(&opts.config, opts.client, opts.api_key, opts.role, &opts.prompt_path)?;
+
+    println!("nerf REPL — type text to reword it, or ':role <name>' / ':client <name>' to switch, ':quit' to exit.");
+
+    let mut editor = Reedline::create();
+    let prompt = DefaultPrompt::new(DefaultPromptSegment::Basic("nerf".to_string()), DefaultPromptSegment::Empty);
+
+    loop {
+        let signal = editor.read_line(&prompt)?;
+        let line = match signal {
+            Signal::Success(line) => line,
+            Signal::CtrlD | Signal::CtrlC => break,
+        };
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix(':') {
+            if handle_command(rest, &opts.config, &mut session, &opts.prompt_path)? {
+                break;
+            }
+            continue;
+        }
+
+        let prompt_text = session.prompt_template.replace("{input}", line);
+        debug!("Final prompt to send: {}", prompt_text);
+
+        let proxy = resolve_proxy(opts.proxy.as_deref(), &session.client);
+        let reworded = if opts.stream {
+            send_to_chatgpt_streaming(&session.client, &session.api_key, proxy.as_deref(), &session.generation, &prompt_text).await
+        } else {
+            send_to_chatgpt(&session.client, &session.api_key, proxy.as_deref(), &session.generation, &prompt_text).await
+        };
+
+        let reworded = match reworded {
+            Ok(reworded) => reworded,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                continue;
+            }
+        };
+
+        if !opts.stream {
+            println!("{}", reworded);
+        }
+
+        if !opts.no_clipboard {
+            if let Err(e) = copy_to_clipboard(&reworded, opts.clipboard_cmd.as_deref()) {
+                eprintln!("warning: failed to copy to clipboard: {}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles a `:command` line. Returns `Ok(true)` if the REPL should exit.
+fn handle_command(command: &str, config: &Config, session: &mut Session, prompt_path: &str) -> Result<bool> {
+    let mut parts = command.splitn(2, ' ');
+    let name = parts.next().unwrap_or("");
+    let arg = parts.next().map(str::trim);
+
+    match name {
+        "quit" | "exit" => Ok(true),
+        "role" => {
+            let role_name = arg.map(str::to_string);
+            match Session::load(config, session.client.clone(), session.api_key.clone(), role_name.clone(), prompt_path) {
+                Ok(new_session) => {
+                    *session = new_session;
+                    info!("Switched to role: {}", role_name.as_deref().unwrap_or("(none)"));
+                }
+                Err(e) => eprintln!("error: {}", e),
+            }
+            Ok(false)
+        }
+        "client" => {
+            let Some(client_name) = arg else {
+                eprintln!("usage: :client <name>");
+                return Ok(false);
+            };
+            match config.client(Some(client_name)) {
+                Ok(client) => match client.resolve_api_key() {
+                    Ok(api_key) => match Session::load(config, client, api_key, session.role_name.clone(), prompt_path) {
+                        Ok(new_session) => {
+                            *session = new_session;
+                            info!("Switched to client: {}", client_name);
+                        }
+                        Err(e) => eprintln!("error: {}", e),
+                    },
+                    Err(e) => eprintln!("error: {}", e),
+                },
+                Err(e) => eprintln!("error: {}", e),
+            }
+            Ok(false)
+        }
+        _ => {
+            eprintln!("unknown command ':{}'", name);
+            Ok(false)
+        }
+    }
+}